@@ -0,0 +1,306 @@
+//! A fixed-capacity, array-backed pairing heap.
+//!
+//! Unlike [`crate::pairing_heap::PairingHeap`], which grows an unbounded
+//! `Vec`-backed arena, [`FixedPairingHeap`] stores its nodes inline in an
+//! `[Entry<T>; N]` array addressed by index, with no heap allocation at
+//! all. That makes the whole structure a flat, pointer-free value that
+//! derives `Copy` when `T: Copy`, suitable for `no_std` and other
+//! environments where a global allocator isn't available.
+//!
+//! This does **not** currently make the type safe to cast to bytes for
+//! shared-memory or on-chain use: it carries no `#[repr(C)]` layout and
+//! no `bytemuck::Pod`/`Zeroable` impl, and `Entry<T>`'s default enum
+//! layout (tagged over `Option<usize>` niches) isn't guaranteed stable
+//! across compiler versions, so transmuting it today would be UB. That
+//! would need an explicit `#[repr(C)]` layout plus a real `unsafe impl
+//! Pod`/`Zeroable` bounded on `T: Pod` once this crate takes on a
+//! `bytemuck` dependency; neither is provided here.
+
+/// Error returned by [`FixedPairingHeap::insert`] when the heap is
+/// already at its fixed capacity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Full;
+
+#[derive(Debug, Clone, Copy)]
+struct Slot<T> {
+    elem: T,
+    first_child: Option<usize>,
+    next_sibling: Option<usize>,
+    /// The node's parent if this is its parent's first child, otherwise
+    /// its left sibling. [`crate::pairing_heap`] keeps these as two
+    /// separate links (`parent` and `prev_sibling`); here they share one.
+    prev: Option<usize>,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Entry<T> {
+    Occupied(Slot<T>),
+    Free { next_free: Option<usize> },
+}
+
+/// A min-oriented pairing heap with a compile-time-fixed capacity of `N`
+/// elements, backed by an inline array rather than a heap allocation.
+///
+/// # Example
+/// ```rust
+/// # use heapo::fixed_pairing_heap::FixedPairingHeap;
+/// let mut h = FixedPairingHeap::<i32, 4>::new();
+/// h.insert(10).unwrap();
+/// h.insert(3).unwrap();
+/// assert_eq!(h.peek(), Some(&3));
+/// assert_eq!(h.pop(), Some(3));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct FixedPairingHeap<T, const N: usize> {
+    entries: [Entry<T>; N],
+    free_head: Option<usize>,
+    root: Option<usize>,
+    len: usize,
+}
+
+impl<T: Ord, const N: usize> FixedPairingHeap<T, N> {
+    /// Creates an empty heap with capacity for `N` elements.
+    pub fn new() -> Self {
+        Self {
+            entries: core::array::from_fn(|i| Entry::Free {
+                next_free: if i + 1 < N { Some(i + 1) } else { None },
+            }),
+            free_head: if N == 0 { None } else { Some(0) },
+            root: None,
+            len: 0,
+        }
+    }
+
+    /// Returns `true` if the heap contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+
+    /// Returns the number of elements currently stored.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns the heap's fixed capacity, `N`.
+    pub fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns a reference to the current minimum element without removing it.
+    pub fn peek(&self) -> Option<&T> {
+        self.root.map(|idx| &self.slot(idx).elem)
+    }
+
+    /// Inserts a new element, or returns [`Full`] if the heap is already
+    /// at capacity.
+    ///
+    /// Amortized O(1).
+    pub fn insert(&mut self, elem: T) -> Result<(), Full> {
+        let idx = self.alloc(Slot {
+            elem,
+            first_child: None,
+            next_sibling: None,
+            prev: None,
+        })?;
+        self.root = self.merge_nodes(self.root, Some(idx));
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Removes (deletes) the minimum element, discarding its value.
+    ///
+    /// Does nothing if the heap is empty.
+    pub fn delete(&mut self) {
+        self.pop();
+    }
+
+    /// Removes and returns the minimum element, or `None` if empty.
+    pub fn pop(&mut self) -> Option<T> {
+        let root = self.root?;
+        let first_child = self.slot(root).first_child;
+        let elem = self.dealloc(root);
+        self.root = self.merge_pairs(first_child);
+        self.len -= 1;
+        Some(elem)
+    }
+
+    fn slot(&self, idx: usize) -> &Slot<T> {
+        match &self.entries[idx] {
+            Entry::Occupied(slot) => slot,
+            Entry::Free { .. } => panic!("dangling fixed pairing heap index"),
+        }
+    }
+
+    fn slot_mut(&mut self, idx: usize) -> &mut Slot<T> {
+        match &mut self.entries[idx] {
+            Entry::Occupied(slot) => slot,
+            Entry::Free { .. } => panic!("dangling fixed pairing heap index"),
+        }
+    }
+
+    fn alloc(&mut self, slot: Slot<T>) -> Result<usize, Full> {
+        let idx = self.free_head.ok_or(Full)?;
+        let next_free = match self.entries[idx] {
+            Entry::Free { next_free } => next_free,
+            Entry::Occupied(_) => unreachable!("free list pointed at an occupied entry"),
+        };
+        self.entries[idx] = Entry::Occupied(slot);
+        self.free_head = next_free;
+        Ok(idx)
+    }
+
+    fn dealloc(&mut self, idx: usize) -> T {
+        let old = core::mem::replace(
+            &mut self.entries[idx],
+            Entry::Free {
+                next_free: self.free_head,
+            },
+        );
+        self.free_head = Some(idx);
+        match old {
+            Entry::Occupied(slot) => slot.elem,
+            Entry::Free { .. } => panic!("double free of fixed pairing heap slot"),
+        }
+    }
+
+    fn link_child(&mut self, parent: usize, child: usize) {
+        let old_first = self.slot(parent).first_child;
+        self.slot_mut(child).next_sibling = old_first;
+        self.slot_mut(child).prev = Some(parent);
+        if let Some(old_first) = old_first {
+            self.slot_mut(old_first).prev = Some(child);
+        }
+        self.slot_mut(parent).first_child = Some(child);
+    }
+
+    fn merge_nodes(&mut self, n1: Option<usize>, n2: Option<usize>) -> Option<usize> {
+        match (n1, n2) {
+            (Some(x), None) | (None, Some(x)) => Some(x),
+            (Some(a), Some(b)) => {
+                if self.slot(a).elem < self.slot(b).elem {
+                    self.link_child(a, b);
+                    Some(a)
+                } else {
+                    self.link_child(b, a);
+                    Some(b)
+                }
+            }
+            (None, None) => None,
+        }
+    }
+
+    /// Same iterative two-pass consolidation as
+    /// `pairing_heap::PairingHeap::merge_pairs`, but threaded through the
+    /// arena's own `next_sibling` links instead of a side buffer: this
+    /// type must not depend on an allocator, and a buffer sized for the
+    /// worst case would cost O(capacity) stack space on every pop no
+    /// matter how few siblings are actually being consolidated.
+    fn merge_pairs(&mut self, head: Option<usize>) -> Option<usize> {
+        // Pass 1: pair up adjacent siblings left-to-right, threading the
+        // winners into a new list via their (now-unused) `next_sibling`.
+        let mut pass_one_head = None;
+        let mut pass_one_tail = None;
+        let mut cur = head;
+        while let Some(a) = cur {
+            let a_next = self.slot(a).next_sibling;
+            self.slot_mut(a).prev = None;
+            self.slot_mut(a).next_sibling = None;
+
+            let winner = if let Some(b) = a_next {
+                let b_next = self.slot(b).next_sibling;
+                self.slot_mut(b).prev = None;
+                self.slot_mut(b).next_sibling = None;
+                cur = b_next;
+                self.merge_nodes(Some(a), Some(b)).unwrap()
+            } else {
+                cur = None;
+                a
+            };
+
+            match pass_one_tail {
+                Some(tail) => self.slot_mut(tail).next_sibling = Some(winner),
+                None => pass_one_head = Some(winner),
+            }
+            pass_one_tail = Some(winner);
+        }
+
+        // Pass 2: reverse the threaded list in place, then fold
+        // left-to-right over the reversed order, which is equivalent to
+        // folding the original order right-to-left.
+        let mut reversed_head = None;
+        let mut cur = pass_one_head;
+        while let Some(idx) = cur {
+            let next = self.slot(idx).next_sibling;
+            self.slot_mut(idx).next_sibling = reversed_head;
+            reversed_head = Some(idx);
+            cur = next;
+        }
+
+        let mut result = None;
+        let mut cur = reversed_head;
+        while let Some(idx) = cur {
+            cur = self.slot(idx).next_sibling;
+            self.slot_mut(idx).next_sibling = None;
+            result = self.merge_nodes(result, Some(idx));
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_heap_has_is_empty_true() {
+        let heap = FixedPairingHeap::<i32, 4>::new();
+        assert_eq!(heap.is_empty(), true);
+    }
+
+    #[test]
+    fn insert_rearranges_the_heap() {
+        let mut heap = FixedPairingHeap::<i32, 8>::new();
+        heap.insert(24).unwrap();
+        heap.insert(5).unwrap();
+        heap.insert(14).unwrap();
+        assert_eq!(heap.peek(), Some(&5));
+    }
+
+    #[test]
+    fn pop_min_actually_pops_min_and_frees_slots() {
+        let mut heap = FixedPairingHeap::<i32, 3>::new();
+        heap.insert(253).unwrap();
+        heap.insert(1231).unwrap();
+        heap.insert(65).unwrap();
+        assert_eq!(heap.pop(), Some(65));
+        assert_eq!(heap.pop(), Some(253));
+        assert_eq!(heap.pop(), Some(1231));
+        assert_eq!(heap.pop(), None);
+        assert_eq!(heap.len(), 0);
+
+        // Popping frees slots, so the heap can accept new inserts even
+        // though it's already seen `capacity()` elements in total.
+        heap.insert(1).unwrap();
+        heap.insert(2).unwrap();
+        heap.insert(3).unwrap();
+        assert_eq!(heap.len(), 3);
+    }
+
+    #[test]
+    fn insert_past_capacity_returns_full() {
+        let mut heap = FixedPairingHeap::<i32, 2>::new();
+        heap.insert(1).unwrap();
+        heap.insert(2).unwrap();
+        assert_eq!(heap.insert(3), Err(Full));
+    }
+
+    #[test]
+    fn heap_of_copy_elements_is_itself_copy() {
+        let mut a = FixedPairingHeap::<i32, 4>::new();
+        a.insert(1).unwrap();
+        let mut b = a;
+        b.insert(2).unwrap();
+        assert_eq!(a.len(), 1);
+        assert_eq!(b.len(), 2);
+    }
+}
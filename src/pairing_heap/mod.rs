@@ -4,17 +4,52 @@
 //! - insert: O(1)
 //! - find-min: O(1)
 //! - delete-min / pop: O(log n) amortized
+//! - decrease-key: O(log n) amortized
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::rc::Rc;
 
 #[derive(Debug, Clone)]
-struct Node<T> {
+struct Slot<T> {
     elem: T,
-    children: Vec<Box<Node<T>>>,
+    parent: Option<usize>,
+    first_child: Option<usize>,
+    next_sibling: Option<usize>,
+    /// The previous sibling in the parent's child list, or `None` if
+    /// this is the first child (or the root, which has no siblings).
+    prev_sibling: Option<usize>,
 }
 
-#[derive(Debug, Clone)]
-/// A min-oriented pairing heap.
+/// An opaque handle to a node previously inserted into a [`PairingHeap`].
+///
+/// Pass it to [`PairingHeap::decrease_key`] to update that node's element
+/// in O(log n) amortized, which is what makes pairing heaps useful for
+/// Dijkstra/Prim-style algorithms. The handle carries a generation counter
+/// alongside its slot index, so if the node it pointed to has since been
+/// popped/deleted and its slot reused by a later `insert`, `decrease_key`
+/// panics instead of silently mutating the wrong, currently-live node.
 ///
-/// Stores elements of type `T` where `T: Ord`.
+/// A handle is only valid for the heap that produced it: [`PairingHeap::merge`]
+/// and [`PairingHeap::meld`] re-base the absorbed heap's internal indices,
+/// which invalidates any handles obtained from it. Unlike a reused slot,
+/// this case is *not* caught by the generation check (the rebased index may
+/// coincidentally still carry a matching generation in the combined heap),
+/// so it remains the caller's responsibility to discard handles from a heap
+/// after merging it away.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Handle(usize, u32);
+
+/// A pairing heap ordered by a stored comparator.
+///
+/// By default (via [`new`](Self::new)) elements are ordered by `T: Ord`,
+/// giving min-heap behavior. Use [`new_by`](Self::new_by) to supply a
+/// custom comparator instead, e.g. to build a max-heap or to order by a
+/// key extracted from `T`, without wrapping every element in
+/// `std::cmp::Reverse`. Nodes live in an internal arena addressed by
+/// index rather than a `Box<Node<T>>` tree, which is what lets
+/// [`decrease_key`](Self::decrease_key) relocate a node without
+/// rebuilding the structure around it.
 ///
 /// # Example
 /// ```rust
@@ -27,12 +62,39 @@ struct Node<T> {
 /// assert_eq!(h.pop(), Some(10));
 /// assert!(h.is_empty());
 /// ```
+#[derive(Clone)]
 pub struct PairingHeap<T> {
-    root: Option<Box<Node<T>>>,
+    slots: Vec<Option<Slot<T>>>,
+    /// Parallel to `slots`, indexed by slot index. Bumped every time a slot
+    /// is freed, so a [`Handle`] minted before the slot was freed and reused
+    /// by a later `insert` can be detected as stale rather than aliasing
+    /// onto whatever element now lives there.
+    generations: Vec<u32>,
+    free: Vec<usize>,
+    root: Option<usize>,
+    len: usize,
+    // `Rc` rather than `Box` so the heap as a whole stays `Clone`: cloning
+    // just shares the comparator rather than requiring it to be `Clone`
+    // itself (closures that capture state generally aren't).
+    cmp: Rc<dyn Fn(&T, &T) -> Ordering>,
+}
+
+impl<T: fmt::Debug> fmt::Debug for PairingHeap<T> {
+    // `cmp` is a trait object and can't implement `Debug`, so it's
+    // omitted here rather than blocking `Debug` on the whole heap.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PairingHeap")
+            .field("slots", &self.slots)
+            .field("generations", &self.generations)
+            .field("free", &self.free)
+            .field("root", &self.root)
+            .field("len", &self.len)
+            .finish()
+    }
 }
 
 impl<T: Ord> PairingHeap<T> {
-    /// Creates an empty pairing heap.
+    /// Creates an empty min-heap ordered by `T`'s `Ord` implementation.
     ///
     /// # Example
     /// ```rust
@@ -41,7 +103,35 @@ impl<T: Ord> PairingHeap<T> {
     /// assert!(h.is_empty());
     /// ```
     pub fn new() -> Self {
-        Self { root: None }
+        Self::new_by(|a, b| a.cmp(b))
+    }
+}
+
+impl<T> PairingHeap<T> {
+    /// Creates an empty heap ordered by `cmp`, where the element for which
+    /// `cmp` reports [`Ordering::Less`] sits closer to the root.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use heapo::pairing_heap::PairingHeap;
+    /// // Max-heap: the larger element should be treated as "less".
+    /// let mut h = PairingHeap::new_by(|a: &i32, b: &i32| b.cmp(a));
+    /// h.insert(3);
+    /// h.insert(10);
+    /// assert_eq!(h.peek(), Some(&10));
+    /// ```
+    pub fn new_by<F>(cmp: F) -> Self
+    where
+        F: Fn(&T, &T) -> Ordering + 'static,
+    {
+        Self {
+            slots: Vec::new(),
+            generations: Vec::new(),
+            free: Vec::new(),
+            root: None,
+            len: 0,
+            cmp: Rc::new(cmp),
+        }
     }
 
     /// Returns `true` if the heap contains no elements.
@@ -49,76 +139,348 @@ impl<T: Ord> PairingHeap<T> {
         self.root.is_none()
     }
 
+    /// Returns the number of elements in the heap.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
     /// Returns a reference to the current minimum element without removing it.
     pub fn peek(&self) -> Option<&T> {
-        self.root.as_ref().map(|n| &n.elem)
+        self.root.map(|idx| &self.slot(idx).elem)
     }
 
-    /// Inserts a new element into the heap.
+    /// Inserts a new element into the heap, returning a [`Handle`] that can
+    /// later be passed to [`decrease_key`](Self::decrease_key).
     ///
     /// Amortized O(1).
-    pub fn insert(&mut self, elem: T) {
-        let new_node = Box::new(Node {
-            elem: elem,
-            children: Vec::new(),
+    pub fn insert(&mut self, elem: T) -> Handle {
+        let idx = self.alloc(Slot {
+            elem,
+            parent: None,
+            first_child: None,
+            next_sibling: None,
+            prev_sibling: None,
         });
-        let old_root = self.root.take();
-        self.root = Some(Self::merge_nodes(old_root, Some(new_node)))
+        self.root = self.merge_nodes(self.root, Some(idx));
+        self.len += 1;
+        Handle(idx, self.generations[idx])
+    }
+
+    /// Decreases (or otherwise updates) the element at `handle` to
+    /// `new_elem` and restores heap order in O(log n) amortized.
+    ///
+    /// If the node is not the root and the new element now violates heap
+    /// order against its parent, the node (with its whole subtree) is cut
+    /// free and melded back in as a new candidate root. Behavior is only
+    /// well-defined when `new_elem` is not greater than the node's current
+    /// element, matching the usual decrease-key contract.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `handle` is stale, i.e. it was obtained from a node that
+    /// has since been popped or deleted, whether or not its slot has been
+    /// reused by a later `insert`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use heapo::pairing_heap::PairingHeap;
+    /// let mut h = PairingHeap::new();
+    /// let a = h.insert(10);
+    /// h.insert(5);
+    /// h.decrease_key(a, 1);
+    /// assert_eq!(h.pop(), Some(1));
+    /// ```
+    pub fn decrease_key(&mut self, handle: Handle, new_elem: T) {
+        let idx = self.resolve(handle);
+        self.slot_mut(idx).elem = new_elem;
+
+        if self.root == Some(idx) {
+            return;
+        }
+
+        if let Some(parent) = self.slot(idx).parent {
+            if !self.less(idx, parent) {
+                return;
+            }
+        }
+
+        self.detach(idx);
+        self.root = self.merge_nodes(self.root, Some(idx));
+    }
+
+    /// Merges `other` into `self`, leaving `other` empty.
+    ///
+    /// This is the meld operation pairing heaps are named for: the two
+    /// root nodes are compared once and the loser becomes a child of the
+    /// winner, so no rebalancing of either heap's interior is needed.
+    /// Classic pairing-heap meld is O(1) for that reason, but this
+    /// implementation keeps nodes in a single `Vec`-backed arena per heap
+    /// addressed by plain `usize` index, so absorbing `other` means
+    /// rebasing and copying every one of its slots (including already-freed
+    /// ones) into `self`'s arena. That makes this call O(size of `other`),
+    /// not O(1) — a known limitation of the arena representation, tracked
+    /// as a possible future redesign (e.g. a shared arena or per-heap arena
+    /// ids) rather than fixed here.
+    ///
+    /// `self` and `other` must have been built with equivalent comparators
+    /// (e.g. both via [`new`](Self::new), or both via [`new_by`](Self::new_by)
+    /// with comparators that agree on ordering). Merging heaps ordered by
+    /// different comparators is not checked or rejected: it silently
+    /// produces a heap that is not correctly ordered by either comparator,
+    /// since `other`'s subtree was structured under its own comparator and
+    /// is never reconciled against `self.cmp`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use heapo::pairing_heap::PairingHeap;
+    /// let mut a = PairingHeap::new();
+    /// a.insert(5);
+    /// let mut b = PairingHeap::new();
+    /// b.insert(2);
+    /// a.merge(&mut b);
+    /// assert_eq!(a.peek(), Some(&2));
+    /// assert!(b.is_empty());
+    /// ```
+    pub fn merge(&mut self, other: &mut PairingHeap<T>) {
+        let offset = self.slots.len();
+        let other_root = other.root.take().map(|idx| idx + offset);
+
+        for slot in other.slots.drain(..) {
+            self.slots.push(slot.map(|mut slot| {
+                slot.parent = slot.parent.map(|idx| idx + offset);
+                slot.first_child = slot.first_child.map(|idx| idx + offset);
+                slot.next_sibling = slot.next_sibling.map(|idx| idx + offset);
+                slot.prev_sibling = slot.prev_sibling.map(|idx| idx + offset);
+                slot
+            }));
+        }
+        self.generations.extend(other.generations.drain(..));
+        self.free.extend(other.free.drain(..).map(|idx| idx + offset));
+
+        self.len += other.len;
+        other.len = 0;
+        let self_root = self.root.take();
+        self.root = self.merge_nodes(self_root, other_root);
+    }
+
+    /// Consumes two heaps and returns their union.
+    ///
+    /// See [`merge`](Self::merge), which this is built on, for the
+    /// complexity caveat (currently O(size of `b`), not O(1)) and the
+    /// requirement that `a` and `b` share an equivalent comparator.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use heapo::pairing_heap::PairingHeap;
+    /// let mut a = PairingHeap::new();
+    /// a.insert(5);
+    /// let mut b = PairingHeap::new();
+    /// b.insert(2);
+    /// let merged = PairingHeap::meld(a, b);
+    /// assert_eq!(merged.peek(), Some(&2));
+    /// ```
+    pub fn meld(mut a: PairingHeap<T>, mut b: PairingHeap<T>) -> PairingHeap<T> {
+        a.merge(&mut b);
+        a
     }
 
     /// Removes (deletes) the minimum element, discarding its value.
     ///
     /// Does nothing if the heap is empty.
     pub fn delete(&mut self) {
-        if let Some(node) = self.root.take() {
-            self.root = Self::merge_pairs(node.children)
-        }
+        self.pop();
     }
 
     /// Removes and returns the minimum element, or `None` if empty.
     pub fn pop(&mut self) -> Option<T> {
-        match self.root.take() {
-            None => None,
-            Some(node) => {
-                let elem = node.elem;
-                self.root = Self::merge_pairs(node.children);
-                Some(elem)
+        let root = self.root?;
+        let first_child = self.slot(root).first_child;
+        let elem = self.dealloc(root);
+        self.root = self.merge_pairs(first_child);
+        self.len -= 1;
+        Some(elem)
+    }
+
+    /// Pops every element off the heap in sorted order, collecting them
+    /// into a `Vec`. An O(n log n) heapsort directly off the heap.
+    pub fn into_sorted_vec(self) -> Vec<T> {
+        self.into_iter().collect()
+    }
+
+    fn slot(&self, idx: usize) -> &Slot<T> {
+        self.slots[idx].as_ref().expect("dangling pairing heap index")
+    }
+
+    fn slot_mut(&mut self, idx: usize) -> &mut Slot<T> {
+        self.slots[idx].as_mut().expect("dangling pairing heap index")
+    }
+
+    /// Validates a [`Handle`] against the current generation of the slot it
+    /// points to, returning the slot index if it's still live.
+    fn resolve(&self, handle: Handle) -> usize {
+        let Handle(idx, generation) = handle;
+        assert_eq!(
+            self.generations.get(idx).copied(),
+            Some(generation),
+            "stale PairingHeap handle: its node has already been popped/deleted"
+        );
+        idx
+    }
+
+    fn less(&self, a: usize, b: usize) -> bool {
+        (self.cmp)(&self.slot(a).elem, &self.slot(b).elem) == Ordering::Less
+    }
+
+    fn alloc(&mut self, slot: Slot<T>) -> usize {
+        if let Some(idx) = self.free.pop() {
+            self.slots[idx] = Some(slot);
+            idx
+        } else {
+            self.slots.push(Some(slot));
+            self.generations.push(0);
+            self.slots.len() - 1
+        }
+    }
+
+    fn dealloc(&mut self, idx: usize) -> T {
+        let slot = self.slots[idx].take().expect("double free of pairing heap slot");
+        self.generations[idx] = self.generations[idx].wrapping_add(1);
+        self.free.push(idx);
+        slot.elem
+    }
+
+    /// Makes `child` the new first child of `parent`, fixing up the
+    /// previous first child's `prev_sibling` link. O(1): `child`'s
+    /// `parent` link is set directly rather than discovered by walking.
+    fn link_child(&mut self, parent: usize, child: usize) {
+        let old_first = self.slot(parent).first_child;
+        self.slot_mut(child).next_sibling = old_first;
+        self.slot_mut(child).prev_sibling = None;
+        self.slot_mut(child).parent = Some(parent);
+        if let Some(old_first) = old_first {
+            self.slot_mut(old_first).prev_sibling = Some(child);
+        }
+        self.slot_mut(parent).first_child = Some(child);
+    }
+
+    /// Unlinks `idx` from its parent's child list / sibling chain,
+    /// fixing up its neighbors, and clears its own links.
+    fn detach(&mut self, idx: usize) {
+        let slot = self.slot(idx);
+        let (parent, prev, next) = (slot.parent, slot.prev_sibling, slot.next_sibling);
+
+        match prev {
+            Some(prev) => self.slot_mut(prev).next_sibling = next,
+            None => {
+                if let Some(parent) = parent {
+                    self.slot_mut(parent).first_child = next;
+                }
             }
         }
+        if let Some(next) = next {
+            self.slot_mut(next).prev_sibling = prev;
+        }
+
+        let slot = self.slot_mut(idx);
+        slot.parent = None;
+        slot.prev_sibling = None;
+        slot.next_sibling = None;
     }
 
-    fn merge_nodes(n1: Option<Box<Node<T>>>, n2: Option<Box<Node<T>>>) -> Box<Node<T>> {
+    fn merge_nodes(&mut self, n1: Option<usize>, n2: Option<usize>) -> Option<usize> {
         match (n1, n2) {
-            (Some(x), None) | (None, Some(x)) => x,
-            (Some(mut a), Some(mut b)) => {
-                if a.elem < b.elem {
-                    a.children.push(b);
-                    a
+            (Some(x), None) | (None, Some(x)) => Some(x),
+            (Some(a), Some(b)) => {
+                if self.less(a, b) {
+                    self.link_child(a, b);
+                    Some(a)
                 } else {
-                    b.children.push(a);
-                    b
+                    self.link_child(b, a);
+                    Some(b)
                 }
             }
-            (None, None) => unreachable!(),
+            (None, None) => None,
         }
     }
 
-    fn merge_pairs(mut heaps: Vec<Box<Node<T>>>) -> Option<Box<Node<T>>> {
-        match heaps.len() {
-            0 => None,
-            1 => Some(heaps.pop().unwrap()),
-            _ => {
-                let a = heaps.pop();
-                let b = heaps.pop();
-                let merged = Self::merge_nodes(a, b);
+    /// Consolidates a child list using the classic iterative two-pass
+    /// scheme: pair up adjacent siblings left-to-right, then fold the
+    /// paired results right-to-left. Written iteratively (rather than the
+    /// more natural-looking recursive descent) because a degenerate child
+    /// list — e.g. from many `insert`s never melded into pairs — would
+    /// otherwise recurse to depth n/2 and risk a stack overflow.
+    fn merge_pairs(&mut self, head: Option<usize>) -> Option<usize> {
+        let mut chain = Vec::new();
+        let mut cur = head;
+        while let Some(idx) = cur {
+            cur = self.slot(idx).next_sibling;
+            let slot = self.slot_mut(idx);
+            slot.parent = None;
+            slot.prev_sibling = None;
+            slot.next_sibling = None;
+            chain.push(idx);
+        }
 
-                let rest = Self::merge_pairs(heaps);
-                Some(Self::merge_nodes(Some(merged), rest))
-            }
+        let mut pairs = Vec::with_capacity(chain.len().div_ceil(2));
+        let mut siblings = chain.into_iter();
+        while let Some(a) = siblings.next() {
+            let b = siblings.next();
+            pairs.push(self.merge_nodes(Some(a), b));
+        }
+
+        let mut result = None;
+        for merged in pairs.into_iter().rev() {
+            result = self.merge_nodes(merged, result);
+        }
+        result
+    }
+}
+
+impl<T: Ord> FromIterator<T> for PairingHeap<T> {
+    /// Builds a min-heap from an iterator by repeated [`insert`](Self::insert).
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut heap = Self::new();
+        heap.extend(iter);
+        heap
+    }
+}
+
+impl<T> Extend<T> for PairingHeap<T> {
+    /// Inserts every element of `iter` into the heap.
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for elem in iter {
+            self.insert(elem);
         }
     }
 }
 
+/// An owning iterator that drains a [`PairingHeap`] in sorted order.
+///
+/// Created by [`PairingHeap::into_iter`](IntoIterator::into_iter).
+pub struct IntoIter<T>(PairingHeap<T>);
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.0.pop()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.0.len, Some(self.0.len))
+    }
+}
+
+impl<T> IntoIterator for PairingHeap<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    /// Returns an iterator that pops elements off the heap in sorted order.
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter(self)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::i32;
@@ -173,6 +535,39 @@ mod tests {
         assert_eq!(heap.is_empty(), true);
     }
 
+    #[test]
+    fn merge_combines_two_heaps_and_empties_the_other() {
+        let mut a = PairingHeap::new();
+        a.insert(10);
+        a.insert(3);
+
+        let mut b = PairingHeap::new();
+        b.insert(7);
+        b.insert(1);
+
+        a.merge(&mut b);
+        assert!(b.is_empty());
+
+        let mut out = Vec::new();
+        while let Some(x) = a.pop() {
+            out.push(x);
+        }
+        assert_eq!(out, vec![1, 3, 7, 10]);
+    }
+
+    #[test]
+    fn meld_consumes_both_heaps() {
+        let mut a = PairingHeap::new();
+        a.insert(4);
+        let mut b = PairingHeap::new();
+        b.insert(2);
+
+        let mut merged = PairingHeap::meld(a, b);
+        assert_eq!(merged.pop(), Some(2));
+        assert_eq!(merged.pop(), Some(4));
+        assert_eq!(merged.pop(), None);
+    }
+
     #[test]
     fn pop_min_actually_pops_min() {
         let mut heap = PairingHeap::new();
@@ -186,4 +581,184 @@ mod tests {
         assert_eq!(heap.pop(), None);
         assert_eq!(heap.is_empty(), true);
     }
+
+    #[test]
+    fn pop_does_not_overflow_the_stack_on_a_degenerate_child_list() {
+        // Ascending inserts never trigger a merge_nodes swap, so every
+        // element ends up as a direct child of the root: the worst case
+        // for a recursive consolidation pass.
+        let mut heap = PairingHeap::new();
+        for i in 0..1_000_000 {
+            heap.insert(i);
+        }
+        for i in 0..1_000_000 {
+            assert_eq!(heap.pop(), Some(i));
+        }
+        assert_eq!(heap.pop(), None);
+    }
+
+    #[test]
+    fn len_tracks_inserts_and_pops() {
+        let mut heap = PairingHeap::new();
+        assert_eq!(heap.len(), 0);
+        heap.insert(3);
+        heap.insert(1);
+        assert_eq!(heap.len(), 2);
+        heap.pop();
+        assert_eq!(heap.len(), 1);
+        heap.delete();
+        assert_eq!(heap.len(), 0);
+    }
+
+    #[test]
+    fn from_iterator_and_extend_build_a_heap() {
+        let mut heap: PairingHeap<i32> = vec![5, 1, 4, 2, 3].into_iter().collect();
+        assert_eq!(heap.len(), 5);
+        heap.extend(vec![0, 6]);
+        assert_eq!(heap.len(), 7);
+        assert_eq!(heap.pop(), Some(0));
+    }
+
+    #[test]
+    fn into_iterator_drains_in_sorted_order() {
+        let heap: PairingHeap<i32> = vec![5, 1, 4, 2, 3].into_iter().collect();
+        let sorted: Vec<i32> = heap.into_iter().collect();
+        assert_eq!(sorted, vec![1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn into_sorted_vec_heapsorts_the_elements() {
+        let heap: PairingHeap<i32> = vec![9, -1, 3, 3, 0].into_iter().collect();
+        assert_eq!(heap.into_sorted_vec(), vec![-1, 0, 3, 3, 9]);
+    }
+
+    #[test]
+    fn heap_is_debug_and_clone() {
+        let mut heap = PairingHeap::new();
+        heap.insert(3);
+        heap.insert(1);
+
+        let mut clone = heap.clone();
+        assert!(format!("{:?}", heap).contains("PairingHeap"));
+
+        // The clone is independent: popping one doesn't affect the other.
+        assert_eq!(clone.pop(), Some(1));
+        assert_eq!(heap.pop(), Some(1));
+        assert_eq!(heap.pop(), Some(3));
+        assert_eq!(clone.pop(), Some(3));
+    }
+
+    #[test]
+    fn new_by_supports_max_heap_ordering() {
+        let mut heap = PairingHeap::new_by(|a: &i32, b: &i32| b.cmp(a));
+        heap.insert(5);
+        heap.insert(20);
+        heap.insert(1);
+        assert_eq!(heap.pop(), Some(20));
+        assert_eq!(heap.pop(), Some(5));
+        assert_eq!(heap.pop(), Some(1));
+    }
+
+    #[test]
+    fn new_by_supports_ordering_by_key() {
+        let mut heap = PairingHeap::new_by(|a: &(&str, i32), b: &(&str, i32)| a.1.cmp(&b.1));
+        heap.insert(("c", 3));
+        heap.insert(("a", 1));
+        heap.insert(("b", 2));
+        assert_eq!(heap.pop(), Some(("a", 1)));
+        assert_eq!(heap.pop(), Some(("b", 2)));
+        assert_eq!(heap.pop(), Some(("c", 3)));
+    }
+
+    #[test]
+    fn decrease_key_promotes_a_deep_node_to_the_root() {
+        let mut heap = PairingHeap::new();
+        // Descending inserts make each new element the root, leaving `a`
+        // three levels down: 20 -> 30 -> 40 -> 50.
+        let a = heap.insert(50);
+        heap.insert(40);
+        heap.insert(30);
+        heap.insert(20);
+
+        heap.decrease_key(a, 1);
+        assert_eq!(heap.peek(), Some(&1));
+
+        let mut out = Vec::new();
+        while let Some(x) = heap.pop() {
+            out.push(x);
+        }
+        assert_eq!(out, vec![1, 20, 30, 40]);
+    }
+
+    #[test]
+    fn decrease_key_on_root_is_a_no_op_relink() {
+        let mut heap = PairingHeap::new();
+        let a = heap.insert(5);
+        heap.insert(10);
+
+        heap.decrease_key(a, 1);
+        assert_eq!(heap.peek(), Some(&1));
+    }
+
+    #[test]
+    fn decrease_key_drives_dijkstra_style_updates() {
+        // Simulates relaxing distances in a shortest-path search: nodes are
+        // inserted with tentative distances and relaxed via decrease_key as
+        // shorter paths are discovered.
+        let mut heap = PairingHeap::new();
+        let handles: Vec<_> = [100, 200, 300, 400].iter().map(|&d| heap.insert(d)).collect();
+
+        heap.decrease_key(handles[2], 5);
+        heap.decrease_key(handles[3], 50);
+
+        assert_eq!(heap.pop(), Some(5));
+        assert_eq!(heap.pop(), Some(50));
+        assert_eq!(heap.pop(), Some(100));
+        assert_eq!(heap.pop(), Some(200));
+        assert_eq!(heap.pop(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "stale PairingHeap handle")]
+    fn decrease_key_panics_on_a_stale_handle_whose_slot_was_reused() {
+        let mut heap = PairingHeap::new();
+        let a = heap.insert(1);
+        heap.pop(); // frees `a`'s slot
+        heap.insert(2); // reuses that slot, bumping its generation
+        heap.decrease_key(a, -1000);
+    }
+
+    #[test]
+    fn merging_heaps_with_mismatched_comparators_is_not_reconciled() {
+        // Documented caveat on `merge`: the absorbed heap's subtree was
+        // built under its own comparator and is not re-sorted against
+        // `self`'s, so mixing a min-heap and a max-heap produces a result
+        // that isn't sorted by either.
+        let mut min = PairingHeap::new();
+        min.extend([1, 2, 3]);
+        let mut max = PairingHeap::new_by(|a: &i32, b: &i32| b.cmp(a));
+        max.extend([10, 20, 30]);
+
+        min.merge(&mut max);
+        assert_ne!(min.into_sorted_vec(), vec![1, 2, 3, 10, 20, 30]);
+    }
+
+    #[test]
+    fn decrease_key_is_fast_on_a_node_with_many_left_siblings() {
+        // Regression test for an O(n) parent lookup: insert a node, then
+        // give it many left siblings by inserting ascending values (none
+        // of which ever displace the first node from the child list), so
+        // a `prev`-chain walk to find the parent would scan all of them.
+        // With an explicit `parent` link this should stay O(1) regardless
+        // of how many siblings precede the node.
+        let mut heap = PairingHeap::new();
+        heap.insert(0);
+        let target = heap.insert(1_000_000);
+        for i in 1..200_000 {
+            heap.insert(i);
+        }
+
+        heap.decrease_key(target, -1);
+        assert_eq!(heap.peek(), Some(&-1));
+    }
 }